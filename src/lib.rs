@@ -1,10 +1,19 @@
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
 
-//! Spherical direct geodesic (destination point) calculation.
+//! Spherical geodesic (destination point) calculation.
 //!
 //! This crate solves the *direct* geodesic problem on a spherical Earth model:
 //! given a start point, a distance, and a bearing, compute the destination point.
+//! It also solves the *inverse* problem via [`inverse`]: given a start and end
+//! point, recover the distance and the initial/final bearings between them.
+//! [`destination_ellipsoidal`] solves the direct problem on the WGS84
+//! [`Ellipsoid`] via Vincenty's formulae, for callers who need better accuracy
+//! than the spherical model over long distances. [`intermediate`] and
+//! [`waypoints`] find points along the great-circle arc between two
+//! coordinates. [`LocalProjection`] converts between lat/lon and local
+//! north/east meters around a fixed origin. [`DestinationSolver`] amortizes
+//! the start point's trigonometry across many direct-problem calls.
 //!
 //! ## Units & conventions
 //!
@@ -118,11 +127,30 @@ pub fn destination_with_radius(
         return start;
     }
 
+    destination_from_trig(
+        start.lat.sin(),
+        start.lat.cos(),
+        start.lon,
+        distance_m,
+        bearing_rad,
+        radius_m,
+    )
+}
+
+/// Computes a destination point from the start point's precomputed `sin`/`cos`
+/// latitude, so repeated calls from the same start point can hoist that trig
+/// out of the loop. See [`DestinationSolver`] for the public-facing cache.
+fn destination_from_trig(
+    sin_lat1: f64,
+    cos_lat1: f64,
+    lon1: f64,
+    distance_m: f64,
+    bearing_rad: f64,
+    radius_m: f64,
+) -> LatLon {
     // Angular distance in radians.
     let delta = distance_m / radius_m;
 
-    let sin_lat1 = start.lat.sin();
-    let cos_lat1 = start.lat.cos();
     let sin_delta = delta.sin();
     let cos_delta = delta.cos();
 
@@ -134,11 +162,366 @@ pub fn destination_with_radius(
     let y = bearing_rad.sin() * sin_delta * cos_lat1;
     let x = cos_delta - sin_lat1 * lat2.sin();
     // Normalize longitude to the conventional [-π, π] interval.
-    let lon2 = wrap_pi(start.lon + y.atan2(x));
+    let lon2 = wrap_pi(lon1 + y.atan2(x));
+
+    LatLon::new(lat2, lon2)
+}
+
+/// A reusable solver for computing many destinations from a single start point.
+///
+/// [`destination_with_radius`] recomputes `sin`/`cos` of the start latitude on
+/// every call; when fanning out many rays from the same origin (coverage maps,
+/// sensor sweeps), that trig dominates the cost. `DestinationSolver` hoists it
+/// out by precomputing it once in [`DestinationSolver::new`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DestinationSolver {
+    start: LatLon,
+    sin_lat1: f64,
+    cos_lat1: f64,
+    radius_m: f64,
+}
+
+impl DestinationSolver {
+    /// Creates a solver for `start` using the mean Earth radius.
+    #[must_use]
+    pub fn new(start: LatLon) -> Self {
+        Self::with_radius(start, EARTH_RADIUS_M)
+    }
+
+    /// Creates a solver for `start` using a custom spherical radius.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radius_m` is not positive.
+    #[must_use]
+    pub fn with_radius(start: LatLon, radius_m: f64) -> Self {
+        assert!(radius_m > 0.0, "radius_m must be positive");
+
+        Self {
+            start,
+            sin_lat1: start.lat.sin(),
+            cos_lat1: start.lat.cos(),
+            radius_m,
+        }
+    }
+
+    /// Returns the destination point for a given distance and bearing, reusing
+    /// the start point's precomputed trig.
+    #[must_use]
+    pub fn destination(&self, distance_m: f64, bearing_rad: f64) -> LatLon {
+        if distance_m == 0.0 {
+            // Trivial case: no displacement, return the start point unchanged.
+            return self.start;
+        }
+
+        destination_from_trig(
+            self.sin_lat1,
+            self.cos_lat1,
+            self.start.lon,
+            distance_m,
+            bearing_rad,
+            self.radius_m,
+        )
+    }
+
+    /// Returns the destination points for a slice of `(distance_m, bearing_rad)` pairs.
+    #[must_use]
+    pub fn destinations(&self, rays: &[(f64, f64)]) -> Vec<LatLon> {
+        rays.iter()
+            .map(|&(distance_m, bearing_rad)| self.destination(distance_m, bearing_rad))
+            .collect()
+    }
+}
+
+/// Reference ellipsoid parameters for the Vincenty ellipsoidal geodesic model.
+///
+/// # Notes
+///
+/// - `a` is the semi-major axis in meters.
+/// - `f` is the flattening, `(a - b) / a` where `b` is the semi-minor axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ellipsoid {
+    /// Semi-major axis in meters.
+    pub a: f64,
+    /// Flattening.
+    pub f: f64,
+}
+
+impl Ellipsoid {
+    /// The WGS84 reference ellipsoid used by GPS and most mapping systems.
+    pub const WGS84: Ellipsoid = Ellipsoid {
+        a: 6_378_137.0,
+        f: 1.0 / 298.257_223_563,
+    };
+}
+
+/// Returns the destination point on a reference ellipsoid using Vincenty's direct method.
+///
+/// Inputs are in radians (lat/lon), meters (distance), and radians (bearing).
+/// Bearing is measured clockwise from geographic North. This is more accurate
+/// than [`destination`] for long distances, at the cost of an iterative solve.
+///
+/// # Panics
+///
+/// Panics if the iteration fails to converge within 20 steps, which should not
+/// happen for any physically valid input.
+#[must_use]
+pub fn destination_ellipsoidal(
+    start: LatLon,
+    distance_m: f64,
+    bearing_rad: f64,
+    ellipsoid: Ellipsoid,
+) -> LatLon {
+    let a = ellipsoid.a;
+    let f = ellipsoid.f;
+    let b = a * (1.0 - f);
+
+    let tan_u1 = (1.0 - f) * start.lat.tan();
+    let u1 = tan_u1.atan();
+    let sin_u1 = u1.sin();
+    let cos_u1 = u1.cos();
+    let sigma1 = tan_u1.atan2(bearing_rad.cos());
+
+    let sin_alpha = cos_u1 * bearing_rad.sin();
+    let cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+    let u_sq = cos_sq_alpha * (a * a - b * b) / (b * b);
+
+    let cap_a = 1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+    let cap_b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+
+    let mut sigma = distance_m / (b * cap_a);
+    let mut converged = false;
+    for _ in 0..20 {
+        let cos_2sigma_m = (2.0 * sigma1 + sigma).cos();
+        let sin_sigma = sigma.sin();
+        let cos_sigma = sigma.cos();
+
+        let delta_sigma = cap_b
+            * sin_sigma
+            * (cos_2sigma_m
+                + cap_b / 4.0
+                    * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)
+                        - cap_b / 6.0
+                            * cos_2sigma_m
+                            * (-3.0 + 4.0 * sin_sigma * sin_sigma)
+                            * (-3.0 + 4.0 * cos_2sigma_m * cos_2sigma_m)));
+
+        let sigma_new = distance_m / (b * cap_a) + delta_sigma;
+        if (sigma_new - sigma).abs() < 1e-12 {
+            sigma = sigma_new;
+            converged = true;
+            break;
+        }
+        sigma = sigma_new;
+    }
+    assert!(converged, "Vincenty direct solution failed to converge");
+
+    let sin_sigma = sigma.sin();
+    let cos_sigma = sigma.cos();
+    let cos_2sigma_m = (2.0 * sigma1 + sigma).cos();
+
+    let sigma_term = sin_u1 * sin_sigma - cos_u1 * cos_sigma * bearing_rad.cos();
+    let lat2 = (sin_u1 * cos_sigma + cos_u1 * sin_sigma * bearing_rad.cos())
+        .atan2((1.0 - f) * (sin_alpha * sin_alpha + sigma_term * sigma_term).sqrt());
+
+    let lambda = (sin_sigma * bearing_rad.sin())
+        .atan2(cos_u1 * cos_sigma - sin_u1 * sin_sigma * bearing_rad.cos());
+    let cap_c = f / 16.0 * cos_sq_alpha * (4.0 + f * (4.0 - 3.0 * cos_sq_alpha));
+    let cos_2sigma_m_term =
+        cos_2sigma_m + cap_c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m);
+    let l = lambda
+        - (1.0 - cap_c) * f * sin_alpha * (sigma + cap_c * sin_sigma * cos_2sigma_m_term);
+
+    let lon2 = wrap_pi(start.lon + l);
 
     LatLon::new(lat2, lon2)
 }
 
+/// Returns the great-circle distance and initial/final bearings between two points.
+///
+/// This solves the *inverse* geodesic problem on the mean-radius sphere: given a
+/// start and end [`LatLon`], compute the distance in meters and the bearings
+/// (in radians, clockwise from North, normalized to `[0, 2π)`) at the start and
+/// end of the great-circle path.
+#[must_use]
+pub fn inverse(start: LatLon, end: LatLon) -> (f64, f64, f64) {
+    inverse_with_radius(start, end, EARTH_RADIUS_M)
+}
+
+/// Returns the great-circle distance and initial/final bearings using a custom
+/// spherical radius.
+///
+/// # Panics
+///
+/// Panics if `radius_m` is not positive.
+#[must_use]
+pub fn inverse_with_radius(start: LatLon, end: LatLon, radius_m: f64) -> (f64, f64, f64) {
+    assert!(radius_m > 0.0, "radius_m must be positive");
+
+    let distance_m = great_circle_distance(start, end, radius_m);
+    let initial_bearing_rad = wrap_2pi(initial_bearing(start, end));
+    // The final bearing is the initial bearing of the reversed path, rotated by π.
+    let final_bearing_rad = wrap_2pi(initial_bearing(end, start) + PI);
+
+    (distance_m, initial_bearing_rad, final_bearing_rad)
+}
+
+/// Returns the point at a given `fraction` of the great-circle arc from `start` to `end`.
+///
+/// `fraction` is `0.0` at `start` and `1.0` at `end`; values outside `[0, 1]`
+/// extrapolate along the same great circle. Uses spherical interpolation
+/// ("slerp") between the two points' unit vectors.
+///
+/// If `start` and `end` coincide, `start` is returned to avoid dividing by zero.
+#[must_use]
+pub fn intermediate(start: LatLon, end: LatLon, fraction: f64) -> LatLon {
+    let delta = angular_distance(start, end);
+    if delta == 0.0 {
+        return start;
+    }
+
+    let sin_delta = delta.sin();
+    let coeff_start = ((1.0 - fraction) * delta).sin() / sin_delta;
+    let coeff_end = (fraction * delta).sin() / sin_delta;
+
+    let x = coeff_start * start.lat.cos() * start.lon.cos()
+        + coeff_end * end.lat.cos() * end.lon.cos();
+    let y = coeff_start * start.lat.cos() * start.lon.sin()
+        + coeff_end * end.lat.cos() * end.lon.sin();
+    let z = coeff_start * start.lat.sin() + coeff_end * end.lat.sin();
+
+    let lat = z.atan2((x * x + y * y).sqrt());
+    let lon = wrap_pi(y.atan2(x));
+
+    LatLon::new(lat, lon)
+}
+
+/// Returns `n` evenly spaced points along the great-circle arc from `start` to `end`,
+/// including both endpoints.
+///
+/// `n` must be at least `2`; the returned vector has exactly `n` points, with
+/// the first at `start` and the last at `end`, up to floating-point rounding.
+///
+/// # Panics
+///
+/// Panics if `n` is less than `2`.
+#[must_use]
+pub fn waypoints(start: LatLon, end: LatLon, n: usize) -> Vec<LatLon> {
+    assert!(n >= 2, "n must be at least 2");
+
+    (0..n)
+        .map(|i| intermediate(start, end, i as f64 / (n - 1) as f64))
+        .collect()
+}
+
+/// A local tangent-plane (north/east, in meters) projection about a fixed origin.
+///
+/// Uses the azimuthal-equidistant projection on the crate's sphere, so
+/// distances and bearings from the origin are preserved exactly; distortion
+/// grows with distance from the origin, same as PX4's `map_projection`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LocalProjection {
+    /// The fixed reference point that north/east offsets are measured from.
+    pub origin: LatLon,
+}
+
+impl LocalProjection {
+    /// Creates a new `LocalProjection` centered on `origin`.
+    #[must_use]
+    pub fn new(origin: LatLon) -> Self {
+        Self { origin }
+    }
+
+    /// Projects `p` into local `(north_m, east_m)` offsets using the mean Earth radius.
+    #[must_use]
+    pub fn project(&self, p: LatLon) -> (f64, f64) {
+        self.project_with_radius(p, EARTH_RADIUS_M)
+    }
+
+    /// Projects `p` into local `(north_m, east_m)` offsets using a custom spherical radius.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radius_m` is not positive.
+    #[must_use]
+    pub fn project_with_radius(&self, p: LatLon, radius_m: f64) -> (f64, f64) {
+        assert!(radius_m > 0.0, "radius_m must be positive");
+
+        let lat0 = self.origin.lat;
+        let dlon = p.lon - self.origin.lon;
+        let cos_c = lat0.sin() * p.lat.sin() + lat0.cos() * p.lat.cos() * dlon.cos();
+        let sin_c = (1.0 - cos_c * cos_c).max(0.0).sqrt();
+
+        if sin_c == 0.0 {
+            // p coincides with (or is antipodal to) the origin; no bearing is defined.
+            return (0.0, 0.0);
+        }
+        let k = cos_c.acos() / sin_c;
+
+        let north_m =
+            k * (lat0.cos() * p.lat.sin() - lat0.sin() * p.lat.cos() * dlon.cos()) * radius_m;
+        let east_m = k * p.lat.cos() * dlon.sin() * radius_m;
+        (north_m, east_m)
+    }
+
+    /// Recovers the [`LatLon`] for a local `(north_m, east_m)` offset using the mean Earth radius.
+    #[must_use]
+    pub fn reproject(&self, north_m: f64, east_m: f64) -> LatLon {
+        self.reproject_with_radius(north_m, east_m, EARTH_RADIUS_M)
+    }
+
+    /// Recovers the [`LatLon`] for a local `(north_m, east_m)` offset using a custom
+    /// spherical radius.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radius_m` is not positive.
+    #[must_use]
+    pub fn reproject_with_radius(&self, north_m: f64, east_m: f64, radius_m: f64) -> LatLon {
+        assert!(radius_m > 0.0, "radius_m must be positive");
+
+        let c = (north_m * north_m + east_m * east_m).sqrt() / radius_m;
+        if c == 0.0 {
+            return self.origin;
+        }
+
+        let lat0 = self.origin.lat;
+        let sin_c = c.sin();
+        let cos_c = c.cos();
+
+        let sin_lat = cos_c * lat0.sin() + (north_m / radius_m) * sin_c * lat0.cos() / c;
+        let lat = clamp(sin_lat, -1.0, 1.0).asin();
+
+        let y = (east_m / radius_m) * sin_c;
+        let x = c * lat0.cos() * cos_c - (north_m / radius_m) * lat0.sin() * sin_c;
+        let lon = wrap_pi(self.origin.lon + y.atan2(x));
+
+        LatLon::new(lat, lon)
+    }
+}
+
+fn great_circle_distance(p: LatLon, q: LatLon, radius_m: f64) -> f64 {
+    radius_m * angular_distance(p, q)
+}
+
+fn angular_distance(p: LatLon, q: LatLon) -> f64 {
+    let dlat = q.lat - p.lat;
+    let dlon = q.lon - p.lon;
+    let sin_dlat = (dlat * 0.5).sin();
+    let sin_dlon = (dlon * 0.5).sin();
+    let a = sin_dlat * sin_dlat + p.lat.cos() * q.lat.cos() * sin_dlon * sin_dlon;
+    // Clamp to avoid tiny floating-point drift outside [0, 1].
+    let a = clamp(a, 0.0, 1.0);
+    2.0 * a.sqrt().atan2((1.0 - a).sqrt())
+}
+
+fn initial_bearing(p: LatLon, q: LatLon) -> f64 {
+    let dlon = q.lon - p.lon;
+    let y = dlon.sin() * q.lat.cos();
+    let x = p.lat.cos() * q.lat.sin() - p.lat.sin() * q.lat.cos() * dlon.cos();
+    y.atan2(x)
+}
+
 fn clamp(value: f64, min: f64, max: f64) -> f64 {
     value.max(min).min(max)
 }
@@ -151,28 +534,29 @@ fn wrap_pi(lon: f64) -> f64 {
     wrapped - PI
 }
 
+fn wrap_2pi(angle: f64) -> f64 {
+    let wrapped = angle % (2.0 * PI);
+    if wrapped < 0.0 {
+        wrapped + 2.0 * PI
+    } else {
+        wrapped
+    }
+}
+
 /// Helpers for validating spherical calculations in tests and doctests.
 ///
 /// This module is intentionally `doc(hidden)` outside of tests to keep the
 /// public surface area focused on the main API while still supporting doctests.
 #[cfg_attr(not(test), doc(hidden))]
 pub mod test_utils {
-    use super::{LatLon, EARTH_RADIUS_M};
+    use super::{angular_distance, LatLon, EARTH_RADIUS_M};
 
     /// Returns the great-circle distance between two points in meters.
     ///
     /// This uses the haversine formula to match the crate's spherical Earth model.
     #[must_use]
     pub fn distance_m(p: LatLon, q: LatLon) -> f64 {
-        let dlat = q.lat - p.lat;
-        let dlon = q.lon - p.lon;
-        let sin_dlat = (dlat * 0.5).sin();
-        let sin_dlon = (dlon * 0.5).sin();
-        let a = sin_dlat * sin_dlat + p.lat.cos() * q.lat.cos() * sin_dlon * sin_dlon;
-        // Clamp to avoid tiny floating-point drift outside [0, 1].
-        let a = super::clamp(a, 0.0, 1.0);
-        let c = 2.0 * a.sqrt().atan2((1.0 - a).sqrt());
-        EARTH_RADIUS_M * c
+        angular_distance(p, q) * EARTH_RADIUS_M
     }
 
     /// Returns the initial azimuth (bearing) from `p` to `s`, in degrees.
@@ -0,0 +1,42 @@
+use geodesic_destination::{destination, destination_ellipsoidal, Ellipsoid, LatLon};
+use std::f64::consts::FRAC_PI_2;
+
+const EPS_RAD: f64 = 1e-9;
+
+#[test]
+fn matches_known_vincenty_reference() {
+    // Flinders Peak -> Buninyong, a standard Vincenty worked example.
+    // Expected destination from Vincenty's 1975 paper, given in degrees to
+    // 1e-6 deg (about 0.1 m) and compared within 1e-6 rad.
+    let start = LatLon::new((-37.951_033_4_f64).to_radians(), 144.424_868_f64.to_radians());
+    let distance_m = 54972.271;
+    let bearing_rad = 306.868_158_f64.to_radians();
+
+    let dest = destination_ellipsoidal(start, distance_m, bearing_rad, Ellipsoid::WGS84);
+
+    let expected_lat = (-37.652_821_f64).to_radians();
+    let expected_lon = 143.926_496_f64.to_radians();
+
+    assert!((dest.lat - expected_lat).abs() < 1e-6);
+    assert!((dest.lon - expected_lon).abs() < 1e-6);
+}
+
+#[test]
+fn diverges_slightly_from_spherical_model() {
+    let start = LatLon::new(48.866667_f64.to_radians(), 2.333333_f64.to_radians());
+    let distance_m = 1_000_000.0;
+
+    let spherical = destination(start, distance_m, FRAC_PI_2);
+    let ellipsoidal = destination_ellipsoidal(start, distance_m, FRAC_PI_2, Ellipsoid::WGS84);
+
+    assert!((spherical.lat - ellipsoidal.lat).abs() > EPS_RAD);
+}
+
+#[test]
+fn zero_distance_returns_start() {
+    let start = LatLon::new(0.3, -1.2);
+    let dest = destination_ellipsoidal(start, 0.0, 1.0, Ellipsoid::WGS84);
+
+    assert!((dest.lat - start.lat).abs() < EPS_RAD);
+    assert!((dest.lon - start.lon).abs() < EPS_RAD);
+}
@@ -0,0 +1,64 @@
+use geodesic_destination::{intermediate, waypoints, LatLon};
+use std::f64::consts::FRAC_PI_2;
+
+const EPS: f64 = 1e-9;
+
+fn approx_eq(a: f64, b: f64) -> bool {
+    (a - b).abs() < EPS
+}
+
+#[test]
+fn fraction_zero_and_one_return_endpoints() {
+    let start = LatLon::new(0.0, 0.0);
+    let end = LatLon::new(FRAC_PI_2, FRAC_PI_2);
+
+    let at_start = intermediate(start, end, 0.0);
+    let at_end = intermediate(start, end, 1.0);
+
+    assert!(approx_eq(at_start.lat, start.lat));
+    assert!(approx_eq(at_start.lon, start.lon));
+    assert!(approx_eq(at_end.lat, end.lat));
+    assert!(approx_eq(at_end.lon, end.lon));
+}
+
+#[test]
+fn midpoint_on_equator_is_halfway() {
+    let start = LatLon::new(0.0, 0.0);
+    let end = LatLon::new(0.0, FRAC_PI_2);
+
+    let mid = intermediate(start, end, 0.5);
+
+    assert!(approx_eq(mid.lat, 0.0));
+    assert!(approx_eq(mid.lon, FRAC_PI_2 / 2.0));
+}
+
+#[test]
+fn degenerate_same_point_returns_start() {
+    let start = LatLon::new(0.3, -1.2);
+    let mid = intermediate(start, start, 0.5);
+
+    assert!(approx_eq(mid.lat, start.lat));
+    assert!(approx_eq(mid.lon, start.lon));
+}
+
+#[test]
+fn waypoints_includes_both_endpoints_and_is_evenly_spaced() {
+    let start = LatLon::new(0.0, 0.0);
+    let end = LatLon::new(0.0, FRAC_PI_2);
+
+    let points = waypoints(start, end, 4);
+
+    assert_eq!(points.len(), 4);
+    assert!(approx_eq(points[0].lon, start.lon));
+    assert!(approx_eq(points[3].lon, end.lon));
+    assert!(approx_eq(points[1].lon, FRAC_PI_2 / 3.0));
+    assert!(approx_eq(points[2].lon, 2.0 * FRAC_PI_2 / 3.0));
+}
+
+#[test]
+#[should_panic(expected = "n must be at least 2")]
+fn waypoints_rejects_fewer_than_two_points() {
+    let start = LatLon::new(0.0, 0.0);
+    let end = LatLon::new(0.0, FRAC_PI_2);
+    let _ = waypoints(start, end, 1);
+}
@@ -0,0 +1,65 @@
+use geodesic_destination::{destination, inverse, inverse_with_radius, LatLon, EARTH_RADIUS_M};
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+const DIST_TOLERANCE_M: f64 = 1e-3;
+const BEARING_TOLERANCE_RAD: f64 = 1e-9;
+
+fn ang_diff(a: f64, b: f64) -> f64 {
+    let mut diff = (a - b + PI) % (2.0 * PI);
+    if diff < 0.0 {
+        diff += 2.0 * PI;
+    }
+    (diff - PI).abs()
+}
+
+#[test]
+fn round_trips_destination_due_north() {
+    let start = LatLon::new(48.866667_f64.to_radians(), 2.333333_f64.to_radians());
+    let dest = destination(start, 1_000.0, 0.0);
+
+    let (distance_m, initial_bearing_rad, _final_bearing_rad) = inverse(start, dest);
+
+    assert!((distance_m - 1_000.0).abs() < DIST_TOLERANCE_M);
+    assert!(ang_diff(initial_bearing_rad, 0.0) < BEARING_TOLERANCE_RAD);
+}
+
+#[test]
+fn round_trips_destination_northeast() {
+    let start = LatLon::new(48.866667_f64.to_radians(), 2.333333_f64.to_radians());
+    let dest = destination(start, 1_000.0, FRAC_PI_4);
+
+    let (distance_m, initial_bearing_rad, _final_bearing_rad) = inverse(start, dest);
+
+    assert!((distance_m - 1_000.0).abs() < DIST_TOLERANCE_M);
+    assert!(ang_diff(initial_bearing_rad, FRAC_PI_4) < BEARING_TOLERANCE_RAD);
+}
+
+#[test]
+fn east_quarter_turn_on_equator_reports_final_bearing() {
+    let start = LatLon::new(0.0, 0.0);
+    let distance = EARTH_RADIUS_M * (PI / 2.0);
+    let end = destination(start, distance, FRAC_PI_2);
+
+    let (distance_m, initial_bearing_rad, final_bearing_rad) = inverse(start, end);
+
+    assert!((distance_m - distance).abs() < DIST_TOLERANCE_M);
+    assert!(ang_diff(initial_bearing_rad, FRAC_PI_2) < BEARING_TOLERANCE_RAD);
+    assert!(ang_diff(final_bearing_rad, FRAC_PI_2) < BEARING_TOLERANCE_RAD);
+}
+
+#[test]
+fn zero_distance_has_zero_bearing() {
+    let start = LatLon::new(0.3, -1.2);
+    let (distance_m, initial_bearing_rad, _final_bearing_rad) = inverse(start, start);
+
+    assert!(distance_m.abs() < DIST_TOLERANCE_M);
+    assert_eq!(initial_bearing_rad, 0.0);
+}
+
+#[test]
+#[should_panic(expected = "radius_m must be positive")]
+fn rejects_non_positive_radius() {
+    let start = LatLon::new(0.0, 0.0);
+    let end = LatLon::new(0.1, 0.1);
+    let _ = inverse_with_radius(start, end, 0.0);
+}
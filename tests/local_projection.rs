@@ -0,0 +1,71 @@
+use geodesic_destination::{destination, LatLon, LocalProjection};
+
+const EPS_M: f64 = 1e-3;
+const EPS_RAD: f64 = 1e-9;
+
+#[test]
+fn origin_projects_to_zero_offset() {
+    let origin = LatLon::new(48.866667_f64.to_radians(), 2.333333_f64.to_radians());
+    let projection = LocalProjection::new(origin);
+
+    let (north_m, east_m) = projection.project(origin);
+
+    assert!(north_m.abs() < EPS_M);
+    assert!(east_m.abs() < EPS_M);
+}
+
+#[test]
+fn north_offset_matches_destination() {
+    let origin = LatLon::new(48.866667_f64.to_radians(), 2.333333_f64.to_radians());
+    let projection = LocalProjection::new(origin);
+    let p = destination(origin, 1_000.0, 0.0);
+
+    let (north_m, east_m) = projection.project(p);
+
+    assert!((north_m - 1_000.0).abs() < EPS_M);
+    assert!(east_m.abs() < EPS_M);
+}
+
+#[test]
+fn east_offset_matches_destination() {
+    let origin = LatLon::new(48.866667_f64.to_radians(), 2.333333_f64.to_radians());
+    let projection = LocalProjection::new(origin);
+    let p = destination(origin, 1_000.0, std::f64::consts::FRAC_PI_2);
+
+    let (north_m, east_m) = projection.project(p);
+
+    assert!(north_m.abs() < EPS_M);
+    assert!((east_m - 1_000.0).abs() < EPS_M);
+}
+
+#[test]
+fn project_and_reproject_round_trip() {
+    let origin = LatLon::new(48.866667_f64.to_radians(), 2.333333_f64.to_radians());
+    let projection = LocalProjection::new(origin);
+    let p = destination(origin, 5_000.0, 1.1);
+
+    let (north_m, east_m) = projection.project(p);
+    let back = projection.reproject(north_m, east_m);
+
+    assert!((back.lat - p.lat).abs() < EPS_RAD);
+    assert!((back.lon - p.lon).abs() < EPS_RAD);
+}
+
+#[test]
+fn zero_offset_reprojects_to_origin() {
+    let origin = LatLon::new(0.3, -1.2);
+    let projection = LocalProjection::new(origin);
+
+    let back = projection.reproject(0.0, 0.0);
+
+    assert!((back.lat - origin.lat).abs() < EPS_RAD);
+    assert!((back.lon - origin.lon).abs() < EPS_RAD);
+}
+
+#[test]
+#[should_panic(expected = "radius_m must be positive")]
+fn rejects_non_positive_radius() {
+    let origin = LatLon::new(0.0, 0.0);
+    let projection = LocalProjection::new(origin);
+    let _ = projection.project_with_radius(origin, 0.0);
+}
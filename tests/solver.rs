@@ -0,0 +1,55 @@
+use geodesic_destination::{destination_with_radius, DestinationSolver, LatLon, EARTH_RADIUS_M};
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+const EPS_RAD: f64 = 1e-12;
+
+#[test]
+fn matches_destination_with_radius() {
+    let start = LatLon::new(48.866667_f64.to_radians(), 2.333333_f64.to_radians());
+    let solver = DestinationSolver::new(start);
+
+    let rays = [(1_000.0, 0.0), (1_000.0, FRAC_PI_4), (1_000.0, FRAC_PI_2)];
+
+    for &(distance_m, bearing_rad) in &rays {
+        let expected = destination_with_radius(start, distance_m, bearing_rad, EARTH_RADIUS_M);
+        let actual = solver.destination(distance_m, bearing_rad);
+
+        assert!((actual.lat - expected.lat).abs() < EPS_RAD);
+        assert!((actual.lon - expected.lon).abs() < EPS_RAD);
+    }
+}
+
+#[test]
+fn zero_distance_returns_start() {
+    // Bit-exact, not just approximate: the zero-distance path must return the
+    // original start point rather than reconstruct it via asin(sin(lat)),
+    // which can be off by a few ULPs for some latitudes.
+    let start = LatLon::new(89.9_f64.to_radians(), -1.2);
+    let solver = DestinationSolver::new(start);
+
+    let dest = solver.destination(0.0, 1.0);
+
+    assert_eq!(dest, start);
+}
+
+#[test]
+fn destinations_matches_per_ray_calls() {
+    let start = LatLon::new(0.0, 0.0);
+    let solver = DestinationSolver::new(start);
+
+    let rays = [(1_000.0, 0.0), (2_000.0, FRAC_PI_4), (3_000.0, FRAC_PI_2)];
+    let batch = solver.destinations(&rays);
+
+    assert_eq!(batch.len(), rays.len());
+    for (i, &(distance_m, bearing_rad)) in rays.iter().enumerate() {
+        let single = solver.destination(distance_m, bearing_rad);
+        assert_eq!(batch[i], single);
+    }
+}
+
+#[test]
+#[should_panic(expected = "radius_m must be positive")]
+fn rejects_non_positive_radius() {
+    let start = LatLon::new(0.0, 0.0);
+    let _ = DestinationSolver::with_radius(start, 0.0);
+}